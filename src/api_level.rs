@@ -0,0 +1,54 @@
+//! A typed, comparable API level.
+
+use std::fmt;
+
+/// A typed SDK/distribution API level, e.g. as returned by
+/// [`crate::get_sdk_api_version()`].
+///
+/// Implements [`Ord`] so callers can write capability gates like
+/// `ohos_deviceinfo::get_sdk_api_version().at_least(12)` instead of comparing
+/// raw integers by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApiLevel(pub u32);
+
+impl ApiLevel {
+    /// Whether this API level is at least `level`.
+    pub fn at_least(self, level: u32) -> bool {
+        self.0 >= level
+    }
+}
+
+impl From<ApiLevel> for u32 {
+    fn from(value: ApiLevel) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for ApiLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at_least_compares_the_wrapped_level() {
+        assert!(ApiLevel(12).at_least(12));
+        assert!(ApiLevel(12).at_least(9));
+        assert!(!ApiLevel(9).at_least(12));
+    }
+
+    #[test]
+    fn orders_by_wrapped_level() {
+        assert!(ApiLevel(9) < ApiLevel(12));
+    }
+
+    #[test]
+    fn displays_as_the_wrapped_number() {
+        assert_eq!(ApiLevel(12).to_string(), "12");
+    }
+}
@@ -0,0 +1,98 @@
+//! Structured view of the running OS image's build provenance.
+//!
+//! Follows the Flipper firmware `Version` record (git hash, branch, build
+//! date, dirty flag) in spirit: group the build host/user/hash together with
+//! a build time that's an actual timestamp instead of an opaque string,
+//! whenever the `chrono` feature is available to parse one.
+
+/// The build time of the running OS image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildTime {
+    /// The raw build time tag, parsed into a timestamp.
+    #[cfg(feature = "chrono")]
+    Parsed(chrono::NaiveDateTime),
+    /// The raw build time tag, kept as-is because it didn't match the
+    /// expected `YYYYMMDDHHmmss` format, or because the `chrono` feature is
+    /// disabled.
+    Raw(&'static str),
+    /// The device did not report a build time.
+    Unknown,
+}
+
+impl BuildTime {
+    fn parse(raw: Option<&'static str>) -> Self {
+        let Some(raw) = raw else {
+            return BuildTime::Unknown;
+        };
+        #[cfg(feature = "chrono")]
+        if let Ok(parsed) = chrono::NaiveDateTime::parse_from_str(raw, "%Y%m%d%H%M%S") {
+            return BuildTime::Parsed(parsed);
+        }
+        BuildTime::Raw(raw)
+    }
+}
+
+/// Structured build provenance of the running OS image.
+///
+/// Construct one with [`BuildInfo::collect()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildInfo {
+    pub host: Option<String>,
+    pub user: Option<String>,
+    pub hash: Option<String>,
+    pub build_time: BuildTime,
+}
+
+impl BuildInfo {
+    /// Snapshots the build host/user/hash and build time.
+    pub fn collect() -> Self {
+        Self {
+            host: crate::get_build_host().map(str::to_owned),
+            user: crate::get_build_user().map(str::to_owned),
+            hash: crate::get_build_hash().map(str::to_owned),
+            build_time: BuildTime::parse(crate::get_build_time()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_is_unknown_when_absent() {
+        assert_eq!(BuildTime::parse(None), BuildTime::Unknown);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn parse_reads_the_expected_timestamp_format() {
+        let parsed = BuildTime::parse(Some("20240101120000"));
+        assert_eq!(
+            parsed,
+            BuildTime::Parsed(
+                chrono::NaiveDate::from_ymd_opt(2024, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(12, 0, 0)
+                    .unwrap()
+            )
+        );
+    }
+
+    #[cfg(not(feature = "chrono"))]
+    #[test]
+    fn parse_keeps_the_raw_tag_without_chrono() {
+        assert_eq!(
+            BuildTime::parse(Some("20240101120000")),
+            BuildTime::Raw("20240101120000")
+        );
+    }
+
+    #[test]
+    fn parse_falls_back_to_raw_for_unparseable_tag() {
+        assert_eq!(
+            BuildTime::parse(Some("not-a-timestamp")),
+            BuildTime::Raw("not-a-timestamp")
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Owned, serializable snapshot of every field exposed by this crate.
+
+use crate::{ApiLevel, BuildType, DistributionInfo, OhosDeviceType, SecurityPatchLevel};
+
+/// Owned snapshot of [`DistributionInfo`]'s fields.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistributionSnapshot {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub api_version: ApiLevel,
+    pub release_type: Option<String>,
+}
+
+impl DistributionSnapshot {
+    fn collect() -> Self {
+        Self {
+            name: DistributionInfo::name().map(str::to_owned),
+            version: DistributionInfo::version().map(str::to_owned),
+            api_version: DistributionInfo::api_version(),
+            release_type: DistributionInfo::get_distribution_os_release_type().map(str::to_owned),
+        }
+    }
+}
+
+/// Owned snapshot of every always-available getter in this crate, for use in
+/// telemetry, crash reports, or other payloads that want a single structured
+/// record describing the device rather than a dozen individual calls.
+///
+/// Does not include [`crate::get_udid()`] or [`crate::get_serial()`], since
+/// those are permission-gated and can fail at runtime rather than always
+/// returning a value.
+///
+/// Construct one with [`DeviceInfo::collect()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceInfo {
+    pub device_type: OhosDeviceType,
+    pub manufacturer: Option<String>,
+    pub brand: Option<String>,
+    pub market_name: Option<String>,
+    pub product_series: Option<String>,
+    pub product_model: Option<String>,
+    pub software_model: Option<String>,
+    pub hardware_model: Option<String>,
+    pub bootloader_version: Option<String>,
+    pub abi_list: Option<String>,
+    pub security_patch_tag: Option<SecurityPatchLevel>,
+    pub display_version: Option<String>,
+    pub incremental_version: Option<String>,
+    pub os_release_type: Option<String>,
+    pub os_full_name: Option<String>,
+    pub sdk_api_version: ApiLevel,
+    pub first_api_version: ApiLevel,
+    pub version_id: Option<String>,
+    pub build_type: BuildType,
+    pub build_user: Option<String>,
+    pub build_host: Option<String>,
+    pub build_time: Option<String>,
+    pub build_hash: Option<String>,
+    pub distribution: DistributionSnapshot,
+}
+
+impl DeviceInfo {
+    /// Snapshots every always-available getter in this crate into a single
+    /// owned record.
+    pub fn collect() -> Self {
+        Self {
+            device_type: crate::get_device_type(),
+            manufacturer: crate::get_device_manufacturer().map(str::to_owned),
+            brand: crate::get_brand().map(str::to_owned),
+            market_name: crate::get_market_name().map(str::to_owned),
+            product_series: crate::get_product_series().map(str::to_owned),
+            product_model: crate::get_product_model().map(str::to_owned),
+            software_model: crate::get_software_model().map(str::to_owned),
+            hardware_model: crate::get_hardware_model().map(str::to_owned),
+            bootloader_version: crate::get_bootloader_version().map(str::to_owned),
+            abi_list: crate::get_abi_list().map(str::to_owned),
+            security_patch_tag: crate::get_security_patch_tag(),
+            display_version: crate::get_display_version().map(str::to_owned),
+            incremental_version: crate::get_incremental_version().map(str::to_owned),
+            os_release_type: crate::get_os_release_type().map(str::to_owned),
+            os_full_name: crate::get_os_full_name().map(str::to_owned),
+            sdk_api_version: crate::get_sdk_api_version(),
+            first_api_version: crate::get_first_api_version(),
+            version_id: crate::get_version_id().map(str::to_owned),
+            build_type: crate::get_build_type(),
+            build_user: crate::get_build_user().map(str::to_owned),
+            build_host: crate::get_build_host().map(str::to_owned),
+            build_time: crate::get_build_time().map(str::to_owned),
+            build_hash: crate::get_build_hash().map(str::to_owned),
+            distribution: DistributionSnapshot::collect(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "simulator", not(target_env = "ohos")))]
+mod tests {
+    use super::*;
+    use crate::sim;
+
+    #[test]
+    fn collect_snapshots_simulated_values() {
+        let _guard = sim::lock_for_tests();
+        sim::set_device_type(Some("tablet"));
+        sim::set_manufacturer(Some("HUAWEI"));
+        let info = DeviceInfo::collect();
+        assert_eq!(info.device_type, OhosDeviceType::Tablet);
+        assert_eq!(info.manufacturer, Some("HUAWEI".to_owned()));
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn collect_reflects_absent_distribution_fields() {
+        let _guard = sim::lock_for_tests();
+        sim::set_distribution_os_name(None);
+        let info = DeviceInfo::collect();
+        assert_eq!(info.distribution.name, None);
+        sim::reset_to_defaults();
+    }
+}
@@ -0,0 +1,35 @@
+//! Errors for the permission-gated identifier getters ([`crate::get_udid()`],
+//! [`crate::get_serial()`]).
+
+use std::fmt;
+
+/// An error obtaining a security-checked unique device identifier.
+///
+/// Unlike the crate's other getters, which are always safe to call, the UDID
+/// and serial number are gated behind a permission the caller may not hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeviceInfoError {
+    /// The caller does not hold the permission required to read this value.
+    PermissionDenied {
+        /// The permission that must be requested, e.g. `ohos.permission.sec.ACCESS_UDID`.
+        permission: &'static str,
+    },
+    /// The identifier could not be read, either because the native call
+    /// failed for a reason other than a missing permission, or because it
+    /// succeeded but returned a null or empty result.
+    Unavailable,
+}
+
+impl fmt::Display for DeviceInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeviceInfoError::PermissionDenied { permission } => {
+                write!(f, "missing required permission: {permission}")
+            }
+            DeviceInfoError::Unavailable => write!(f, "device identifier unavailable"),
+        }
+    }
+}
+
+impl std::error::Error for DeviceInfoError {}
@@ -0,0 +1,263 @@
+//! Real backend, backed by the `ohos-deviceinfo-sys` FFI bindings.
+//!
+//! Every function here is a thin wrapper around the matching `OH_Get*` native
+//! call; all but [`udid()`] and [`serial()`], which read into a heap buffer and
+//! return an owned `String`, are allocation-free. Classification and
+//! formatting of the raw values is left to the rest of the crate, so that
+//! logic is shared with the [`crate::sim`] backend.
+
+use crate::DeviceInfoError;
+use ohos_deviceinfo_sys::*;
+use std::ffi::{c_char, CStr};
+
+/// Buffer size used for `OH_GetUdid`/`OH_GetSerial`, matching the startup
+/// subsystem's documented maximum identifier length.
+const IDENTIFIER_BUF_LEN: usize = 65;
+
+/// The well-known OHOS NDK error code for "permission verification failed",
+/// shared across many `OH_*` native APIs (e.g. telephony, multimedia).
+const PERMISSION_DENIED: i32 = 201;
+
+/// Convert a raw c-style string with static lifetime to a Rust str
+///
+/// # Safety
+///
+/// The Caller must pass a valid, null terminated c string. This string
+/// muse be a non-mutable static string, that is valid for the whole remaining
+/// lifetime of the program.
+unsafe fn convert_to_rust_str(static_c_str: *const c_char) -> Option<&'static str> {
+    if static_c_str.is_null() {
+        return None;
+    }
+    // SAFETY: We require a valid, non-mutable c-string with `'static` lifetime, and
+    // we checked for `null`.
+    let c_str = unsafe { CStr::from_ptr(static_c_str) };
+    c_str.to_str().ok().filter(|s| !s.is_empty())
+}
+
+pub(crate) fn device_type() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetDeviceType() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn manufacturer() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetManufacture() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn brand() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetBrand() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn market_name() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetMarketName() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn product_series() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetProductSeries() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn product_model() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetProductModel() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn software_model() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetSoftwareModel() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn hardware_model() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetHardwareModel() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn bootloader_version() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetBootloaderVersion() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn abi_list() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetAbiList() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn security_patch_tag() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetSecurityPatchTag() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn display_version() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetDisplayVersion() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn incremental_version() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetIncrementalVersion() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn os_release_type() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetOsReleaseType() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn os_full_name() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetOSFullName() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn sdk_api_version() -> i32 {
+    // SAFETY: No side effects - always safe to call.
+    unsafe { OH_GetSdkApiVersion() }
+}
+
+pub(crate) fn first_api_version() -> i32 {
+    // SAFETY: No side effects - always safe to call.
+    unsafe { OH_GetFirstApiVersion() }
+}
+
+pub(crate) fn version_id() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetVersionId() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn build_type() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetBuildType() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn build_user() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetBuildUser() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn build_host() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetBuildHost() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn build_time() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetBuildTime() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn build_hash() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetBuildRootHash() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn distribution_os_name() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetDistributionOSName() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn distribution_os_version() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetDistributionOSVersion() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+pub(crate) fn distribution_os_api_version() -> i32 {
+    // SAFETY: No side effects - always safe to call.
+    unsafe { OH_GetDistributionOSApiVersion() }
+}
+
+pub(crate) fn distribution_os_release_type() -> Option<&'static str> {
+    // SAFETY: No side effects - always safe to call.
+    let raw = unsafe { OH_GetDistributionOSReleaseType() };
+    // SAFETY: The c-string has a static lifetime.
+    unsafe { convert_to_rust_str(raw) }
+}
+
+/// Reads a permission-gated identifier into an owned `String` via the given
+/// `OH_Get*(buf, len) -> i32` native call.
+///
+/// Requires `ohos-deviceinfo-sys` to export `OH_GetUdid`/`OH_GetSerial` with
+/// this exact signature (a buffer pointer, its length, and an `i32` status
+/// code where `0` is success and [`PERMISSION_DENIED`] is a missing
+/// permission). These are new symbols as of this function being added here;
+/// bump the `ohos-deviceinfo-sys` dependency and confirm the signature against
+/// its header before relying on [`udid()`]/[`serial()`] in a real build - this
+/// crate has no way to verify it without that dependency present.
+fn read_identifier(
+    get: unsafe extern "C" fn(*mut c_char, i32) -> i32,
+    permission: &'static str,
+) -> Result<String, DeviceInfoError> {
+    let mut buf = vec![0u8; IDENTIFIER_BUF_LEN];
+    // SAFETY: `buf` is valid for `buf.len()` bytes, as required by the native call.
+    let ret = unsafe { get(buf.as_mut_ptr() as *mut c_char, buf.len() as i32) };
+    if ret == PERMISSION_DENIED {
+        return Err(DeviceInfoError::PermissionDenied { permission });
+    }
+    if ret != 0 {
+        return Err(DeviceInfoError::Unavailable);
+    }
+    // Find the NUL terminator ourselves instead of trusting `CStr::from_ptr` to find
+    // one within `buf`'s bounds, in case a successful call ever leaves it unterminated.
+    let len = buf
+        .iter()
+        .position(|&b| b == 0)
+        .ok_or(DeviceInfoError::Unavailable)?;
+    let value = std::str::from_utf8(&buf[..len])
+        .ok()
+        .filter(|s| !s.is_empty())
+        .ok_or(DeviceInfoError::Unavailable)?;
+    Ok(value.to_owned())
+}
+
+pub(crate) fn udid() -> Result<String, DeviceInfoError> {
+    read_identifier(OH_GetUdid, "ohos.permission.sec.ACCESS_UDID")
+}
+
+pub(crate) fn serial() -> Result<String, DeviceInfoError> {
+    read_identifier(OH_GetSerial, "ohos.permission.GET_SERIAL_NUMBER")
+}
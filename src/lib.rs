@@ -2,18 +2,37 @@
 //!
 //! It allows querying basic information about the device, e.g. type and model, as well
 //! as version information about the OS.
-//! Since this Library is specific to OpenHarmony devices, it is empty on other platforms.
+//! Since this Library is specific to OpenHarmony devices, it is empty on other platforms,
+//! unless the `simulator` feature is enabled (see the [`sim`] module).
 //!
 //! Required System Capabilities: SystemCapability.Startup.SystemInfo
 //!
 //! [deviceinfo]: https://docs.openharmony.cn/pages/v5.0/en/application-dev/reference/apis-basic-services-kit/_device_info.md
-#![cfg(target_env = "ohos")]
-#![deny(unsafe_op_in_unsafe_fn)]
+#![cfg(any(target_env = "ohos", feature = "simulator"))]
+#![cfg_attr(target_env = "ohos", deny(unsafe_op_in_unsafe_fn))]
 
-use ohos_deviceinfo_sys::*;
-use std::ffi::{c_char, CStr};
+mod api_level;
+mod build_info;
+mod device_info;
+mod error;
+#[cfg(target_env = "ohos")]
+mod ffi;
+mod security;
+#[cfg(all(feature = "simulator", not(target_env = "ohos")))]
+pub mod sim;
 
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg(target_env = "ohos")]
+use ffi as backend;
+#[cfg(all(feature = "simulator", not(target_env = "ohos")))]
+use sim as backend;
+
+pub use api_level::ApiLevel;
+pub use build_info::{BuildInfo, BuildTime};
+pub use device_info::{DeviceInfo, DistributionSnapshot};
+pub use error::DeviceInfoError;
+pub use security::{BuildType, SecurityPatchLevel, SecurityState};
+
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum OhosDeviceType {
     Phone,
@@ -31,33 +50,51 @@ pub enum OhosDeviceType {
     Unknown,
 }
 
-/// Convert a raw c-style string with static lifetime to a Rust str
-///
-/// # Safety
-///
-/// The Caller must pass a valid, null terminated c string. This string
-/// muse be a non-mutable static string, that is valid for the whole remaining
-/// lifetime of the program.
-unsafe fn convert_to_rust_str(static_c_str: *const c_char) -> Option<&'static str> {
-    if static_c_str.is_null() {
-        return None;
+#[cfg(feature = "serde")]
+impl OhosDeviceType {
+    /// The raw string [`OH_GetDeviceType()`] would report for this variant, or
+    /// `"unknown"` for [`OhosDeviceType::Unknown`], for which it reports none.
+    fn as_str(&self) -> &str {
+        match self {
+            OhosDeviceType::Phone => "phone",
+            OhosDeviceType::Wearable => "wearable",
+            OhosDeviceType::LiteWearable => "liteWearable",
+            OhosDeviceType::Tablet => "tablet",
+            OhosDeviceType::Tv => "tv",
+            OhosDeviceType::Car => "car",
+            OhosDeviceType::SmartVision => "smartVision",
+            OhosDeviceType::Other(other) => other,
+            OhosDeviceType::Unknown => "unknown",
+        }
     }
-    // SAFETY: We require a valid, non-mutable c-string with `'static` lifetime, and
-    // we checked for `null`.
-    let c_str = unsafe { CStr::from_ptr(static_c_str) };
-    c_str.to_str().ok().filter(|s| !s.is_empty())
 }
 
-/// Obtains the device type (e.g. phone or wearable)
-pub fn get_device_type() -> OhosDeviceType {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetDeviceType() };
-    // SAFETY: The c-string has a static lifetime.
-    let Some(device_type) = (unsafe { convert_to_rust_str(raw) }) else {
-        return OhosDeviceType::Unknown;
-    };
+#[cfg(feature = "serde")]
+impl serde::Serialize for OhosDeviceType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OhosDeviceType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        if let Some(known) = classify_known_device_type(&raw) {
+            return Ok(known);
+        }
+        // Only leak for a genuinely unrecognized value, so repeatedly deserializing
+        // known device types (the common case) doesn't grow the heap unbounded.
+        Ok(OhosDeviceType::Other(Box::leak(raw.into_boxed_str())))
+    }
+}
+
+/// Classifies a known raw device type string. Returns `None` for anything
+/// that should become [`OhosDeviceType::Other`], leaving the caller to decide
+/// how to obtain a `'static` string for that case.
+fn classify_known_device_type(device_type: &str) -> Option<OhosDeviceType> {
     // See the documentation of [`OH_GetDeviceType()`].
-    match device_type {
+    Some(match device_type {
         "phone" | "default" => OhosDeviceType::Phone,
         "wearable" => OhosDeviceType::Wearable,
         "liteWearable" => OhosDeviceType::LiteWearable,
@@ -65,173 +102,162 @@ pub fn get_device_type() -> OhosDeviceType {
         "tv" => OhosDeviceType::Tv,
         "car" => OhosDeviceType::Car,
         "smartVision" => OhosDeviceType::SmartVision,
-        other => OhosDeviceType::Other(other),
-    }
+        _ => return None,
+    })
+}
+
+fn classify_device_type(device_type: &'static str) -> OhosDeviceType {
+    classify_known_device_type(device_type).unwrap_or(OhosDeviceType::Other(device_type))
+}
+
+/// Obtains the device type (e.g. phone or wearable)
+pub fn get_device_type() -> OhosDeviceType {
+    let Some(device_type) = backend::device_type() else {
+        return OhosDeviceType::Unknown;
+    };
+    classify_device_type(device_type)
 }
 
 /// Obtains the device manufacturer
 pub fn get_device_manufacturer() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetManufacture() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::manufacturer()
 }
 /// Obtains the device brand
 pub fn get_brand() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetBrand() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::brand()
 }
 /// Obtains the product name speaded in the market
 pub fn get_market_name() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetMarketName() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::market_name()
 }
 
 /// Obtains the product series
 pub fn get_product_series() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetProductSeries() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::product_series()
 }
 /// Obtains the product model
 pub fn get_product_model() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetProductModel() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::product_model()
 }
 /// Obtains the software model
 pub fn get_software_model() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetSoftwareModel() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::software_model()
 }
 /// Obtains the hardware model
 pub fn get_hardware_model() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetHardwareModel() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::hardware_model()
 }
 /// Obtains the bootloader version number as a string
 pub fn get_bootloader_version() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetBootloaderVersion() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::bootloader_version()
 }
 /// Obtains the application binary interface (Abi) list represented as a string.
 pub fn get_abi_list() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetAbiList() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::abi_list()
 }
 
-/// Obtains the security patch tag represented by a string.
-pub fn get_security_patch_tag() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetSecurityPatchTag() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+/// Obtains the device's security patch level, parsed from the raw
+/// `YYYY-MM-DD` security patch tag.
+pub fn get_security_patch_tag() -> Option<SecurityPatchLevel> {
+    security::get_security_patch_tag()
 }
 /// Obtains the product version displayed for customer represented by a string.
 pub fn get_display_version() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetDisplayVersion() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::display_version()
 }
 
 /// Obtains the incremental version represented by a string.
 pub fn get_incremental_version() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetIncrementalVersion() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::incremental_version()
+}
+
+/// Obtains the incremental version, best-effort parsed as a `(major, minor,
+/// patch)` tuple when it matches `X.Y.Z`.
+///
+/// Returns `None` for any other format; use [`get_incremental_version()`] to
+/// get at the raw string in that case.
+pub fn get_incremental_version_semver() -> Option<(u64, u64, u64)> {
+    let raw = get_incremental_version()?;
+    let mut parts = raw.splitn(3, '.');
+    let (Some(major), Some(minor), Some(patch)) = (parts.next(), parts.next(), parts.next()) else {
+        return None;
+    };
+    Some((
+        major.parse().ok()?,
+        minor.parse().ok()?,
+        patch.parse().ok()?,
+    ))
 }
 /// Obtains the OS release type represented by a string.
 ///
 /// The OS release category can be `Release`, `Beta`, or `Canary`.
 /// The specific release type may be `Release`, `Beta1`, or others alike.
 pub fn get_os_release_type() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetOsReleaseType() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::os_release_type()
 }
 /// Obtains the OS full version name represented by a string.
 pub fn get_os_full_name() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetOSFullName() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::os_full_name()
 }
 
 /// Obtains the SDK API version number.
-pub fn get_sdk_api_version() -> u32 {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetSdkApiVersion() };
+pub fn get_sdk_api_version() -> ApiLevel {
     // default to 0 for negative numbers.
-    raw.try_into().unwrap_or_default()
+    ApiLevel(backend::sdk_api_version().try_into().unwrap_or_default())
 }
 
 /// Obtains the first API version number.
-pub fn get_first_api_version() -> u32 {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetFirstApiVersion() };
+pub fn get_first_api_version() -> ApiLevel {
     // default to 0 for negative numbers.
-    raw.try_into().unwrap_or_default()
+    ApiLevel(backend::first_api_version().try_into().unwrap_or_default())
 }
 /// Obtains the version ID by a string.
 pub fn get_version_id() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetVersionId() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::version_id()
 }
 /// Obtains the build type of the current running OS.
-pub fn get_build_type() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetBuildType() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+///
+/// Returns [`BuildType::Unknown`] if the device did not report a build type,
+/// as distinct from [`BuildType::Other`], which is a build type the device
+/// did report but that this library doesn't recognize.
+pub fn get_build_type() -> BuildType {
+    security::get_build_type()
 }
 /// Obtains the build user of the current running OS.
 pub fn get_build_user() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetBuildUser() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::build_user()
 }
 
 /// Obtains the build host of the current running OS.
 pub fn get_build_host() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetBuildHost() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::build_host()
 }
 
 /// Obtains the build time of the current running OS.
 pub fn get_build_time() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetBuildTime() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::build_time()
 }
 /// Obtains the version hash of the current running OS.
 pub fn get_build_hash() -> Option<&'static str> {
-    // SAFETY: No side effects - always safe to call.
-    let raw = unsafe { OH_GetBuildRootHash() };
-    // SAFETY: The c-string has a static lifetime.
-    unsafe { convert_to_rust_str(raw) }
+    backend::build_hash()
+}
+
+/// Obtains the device's unique device identifier (UDID).
+///
+/// Unlike the other getters in this crate, this requires the
+/// `ohos.permission.sec.ACCESS_UDID` permission and can fail at runtime -
+/// callers must handle [`DeviceInfoError::PermissionDenied`] explicitly.
+pub fn get_udid() -> Result<String, DeviceInfoError> {
+    backend::udid()
+}
+
+/// Obtains the device's serial number.
+///
+/// Unlike the other getters in this crate, this requires the
+/// `ohos.permission.GET_SERIAL_NUMBER` permission and can fail at runtime -
+/// callers must handle [`DeviceInfoError::PermissionDenied`] explicitly.
+pub fn get_serial() -> Result<String, DeviceInfoError> {
+    backend::serial()
 }
 
 /// Provides information about this distribution of OpenHarmony OS
@@ -245,39 +271,158 @@ impl DistributionInfo {
     ///
     /// May be `None` if the ISV did not specify a custom distribution name.
     pub fn name() -> Option<&'static str> {
-        // SAFETY: No side effects - always safe to call.
-        let raw = unsafe { OH_GetDistributionOSName() };
-        // SAFETY: The c-string has a static lifetime.
-        unsafe { convert_to_rust_str(raw) }
+        backend::distribution_os_name()
     }
 
     /// Obtains the ISV distribution OS version represented by a string.
     ///
     /// If ISV did not specify, returns the same value as [`get_os_full_name()`]
     pub fn version() -> Option<&'static str> {
-        // SAFETY: No side effects - always safe to call.
-        let raw = unsafe { OH_GetDistributionOSVersion() };
-        // SAFETY: The c-string has a static lifetime.
-        unsafe { convert_to_rust_str(raw) }
+        backend::distribution_os_version()
     }
 
     /// Obtains the ISV distribution OS api version
     ///
     /// If ISV did not specify, returns the same value as [`get_sdk_api_version()`].
-    pub fn api_version() -> u32 {
-        // SAFETY: No side effects - always safe to call.
-        let raw = unsafe { OH_GetDistributionOSApiVersion() };
+    pub fn api_version() -> ApiLevel {
         // default to 0 for negative numbers.
-        raw.try_into().unwrap_or_default()
+        ApiLevel(
+            backend::distribution_os_api_version()
+                .try_into()
+                .unwrap_or_default(),
+        )
     }
 
     /// Obtains the ISV distribution OS release type
     ///
     /// If the ISV did not specify, returns the same value as [`get_os_release_type()`]
     pub fn get_distribution_os_release_type() -> Option<&'static str> {
-        // SAFETY: No side effects - always safe to call.
-        let raw = unsafe { OH_GetDistributionOSReleaseType() };
-        // SAFETY: The c-string has a static lifetime.
-        unsafe { convert_to_rust_str(raw) }
+        backend::distribution_os_release_type()
+    }
+}
+
+/// Composes a single human-readable OS name and version, e.g.
+/// `"OpenHarmony 5.0.0.100 (API level 12)"`.
+///
+/// Prefers [`DistributionInfo::name()`] over [`get_os_full_name()`] when an ISV
+/// distribution name is set, and omits the version when it isn't available.
+pub fn os_pretty_name() -> String {
+    let mut pretty = DistributionInfo::name()
+        .or_else(get_os_full_name)
+        .unwrap_or("OpenHarmony")
+        .to_owned();
+    if let Some(display_version) = get_display_version() {
+        pretty.push(' ');
+        pretty.push_str(display_version);
+    }
+    pretty.push_str(&format!(" (API level {})", get_sdk_api_version()));
+    pretty
+}
+
+/// Builds an HTTP `User-Agent` fragment describing this application and the
+/// device it's running on, e.g.
+/// `"MyApp/1.2.3 (simulator; ALN-AL00; OpenHarmony OpenHarmony-5.0.0.100)"`.
+///
+/// The market name and product model are individually omitted when
+/// unavailable, so the result is always a single well-formed parenthesized
+/// comment suitable for appending to a `User-Agent` header.
+pub fn user_agent_fragment(app_name: &str, app_version: &str) -> String {
+    let device = [get_market_name(), get_product_model()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("; ");
+    let os = match get_os_full_name() {
+        Some(os_full_name) => format!("OpenHarmony {os_full_name}"),
+        None => "OpenHarmony".to_owned(),
+    };
+    let comment = if device.is_empty() {
+        os
+    } else {
+        format!("{device}; {os}")
+    };
+    format!("{app_name}/{app_version} ({comment})")
+}
+
+#[cfg(all(test, feature = "simulator", not(target_env = "ohos")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_udid_returns_the_simulated_value() {
+        let _guard = sim::lock_for_tests();
+        sim::set_udid(Some("deadbeef"));
+        assert_eq!(get_udid(), Ok("deadbeef".to_owned()));
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn get_udid_is_unavailable_when_absent() {
+        let _guard = sim::lock_for_tests();
+        sim::set_udid(None);
+        assert_eq!(get_udid(), Err(DeviceInfoError::Unavailable));
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn get_serial_returns_the_simulated_value() {
+        let _guard = sim::lock_for_tests();
+        sim::set_serial(Some("SN1234"));
+        assert_eq!(get_serial(), Ok("SN1234".to_owned()));
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn get_serial_is_unavailable_when_absent() {
+        let _guard = sim::lock_for_tests();
+        sim::set_serial(None);
+        assert_eq!(get_serial(), Err(DeviceInfoError::Unavailable));
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn os_pretty_name_prefers_distribution_name_and_appends_version_and_api_level() {
+        let _guard = sim::lock_for_tests();
+        sim::set_distribution_os_name(Some("MyOS"));
+        sim::set_display_version(Some("5.0.0.100"));
+        sim::set_sdk_api_version(12);
+        assert_eq!(os_pretty_name(), "MyOS 5.0.0.100 (API level 12)");
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn os_pretty_name_falls_back_to_os_full_name_when_distribution_name_absent() {
+        let _guard = sim::lock_for_tests();
+        sim::set_distribution_os_name(None);
+        sim::set_os_full_name(Some("OpenHarmony-5.0.0.100"));
+        sim::set_display_version(None);
+        assert!(os_pretty_name().starts_with("OpenHarmony-5.0.0.100"));
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn user_agent_fragment_omits_missing_device_fields() {
+        let _guard = sim::lock_for_tests();
+        sim::set_market_name(None);
+        sim::set_product_model(None);
+        sim::set_os_full_name(Some("OpenHarmony-5.0.0.100"));
+        assert_eq!(
+            user_agent_fragment("MyApp", "1.2.3"),
+            "MyApp/1.2.3 (OpenHarmony OpenHarmony-5.0.0.100)"
+        );
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn user_agent_fragment_includes_device_fields_when_present() {
+        let _guard = sim::lock_for_tests();
+        sim::set_market_name(Some("simulator"));
+        sim::set_product_model(Some("ALN-AL00"));
+        sim::set_os_full_name(Some("OpenHarmony-5.0.0.100"));
+        assert_eq!(
+            user_agent_fragment("MyApp", "1.2.3"),
+            "MyApp/1.2.3 (simulator; ALN-AL00; OpenHarmony OpenHarmony-5.0.0.100)"
+        );
+        sim::reset_to_defaults();
     }
 }
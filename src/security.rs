@@ -0,0 +1,267 @@
+//! Typed classification of the device's build and security posture.
+//!
+//! Borrows from the Android RKP `DeviceInfo` model (`bootloader_state`,
+//! `vb_state`, a TEE/StrongBox security level, `fused`), adapted to the two
+//! pieces OpenHarmony's `deviceinfo` API actually exposes: the build type and
+//! the security patch tag.
+
+/// The build type of the running OS image.
+///
+/// Parsed from [`crate::get_build_type()`]'s raw string (`"user"`, `"userdebug"`,
+/// `"eng"`, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BuildType {
+    /// A production, non-debuggable build.
+    User,
+    /// A production build with debugging capabilities enabled.
+    UserDebug,
+    /// A development/engineering build.
+    Eng,
+    /// Some other build type.
+    ///
+    /// If you encounter this, consider updating this library or opening an issue.
+    Other(&'static str),
+    /// The device did not report a build type.
+    Unknown,
+}
+
+impl BuildType {
+    /// Classifies a known raw build type string. Returns `None` for anything
+    /// that should become [`BuildType::Other`], leaving the caller to decide
+    /// how to obtain a `'static` string for that case.
+    fn classify_known(raw: &str) -> Option<Self> {
+        Some(match raw {
+            "user" => BuildType::User,
+            "userdebug" => BuildType::UserDebug,
+            "eng" => BuildType::Eng,
+            _ => return None,
+        })
+    }
+
+    fn parse(raw: &'static str) -> Self {
+        Self::classify_known(raw).unwrap_or(BuildType::Other(raw))
+    }
+
+    /// Whether this is a production ([`BuildType::User`]) build. Debuggable,
+    /// engineering, unrecognized ([`BuildType::Other`]) and unreported
+    /// ([`BuildType::Unknown`]) build types all return `false`.
+    ///
+    /// Useful as a single gate for disabling debug-only code paths:
+    /// `if !ohos_deviceinfo::get_build_type().is_production() { /* ... */ }`.
+    pub fn is_production(&self) -> bool {
+        matches!(self, BuildType::User)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl BuildType {
+    /// The raw string [`OH_GetBuildType()`] would report for this variant, or
+    /// `"unknown"` for [`BuildType::Unknown`], for which it reports none.
+    fn as_str(&self) -> &str {
+        match self {
+            BuildType::User => "user",
+            BuildType::UserDebug => "userdebug",
+            BuildType::Eng => "eng",
+            BuildType::Other(other) => other,
+            BuildType::Unknown => "unknown",
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for BuildType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BuildType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        if let Some(known) = BuildType::classify_known(&raw) {
+            return Ok(known);
+        }
+        // Only leak for a genuinely unrecognized value, so repeatedly deserializing
+        // known build types (the common case) doesn't grow the heap unbounded.
+        Ok(BuildType::Other(Box::leak(raw.into_boxed_str())))
+    }
+}
+
+pub(crate) fn get_build_type() -> BuildType {
+    match crate::backend::build_type() {
+        Some(raw) => BuildType::parse(raw),
+        None => BuildType::Unknown,
+    }
+}
+
+/// The device's security patch level.
+///
+/// Parsed from [`crate::get_security_patch_tag()`]'s raw `YYYY-MM-DD` tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum SecurityPatchLevel {
+    /// A successfully parsed `YYYY-MM-DD` security patch date.
+    Date { year: u16, month: u8, day: u8 },
+    /// A security patch tag that didn't match the expected `YYYY-MM-DD` format.
+    ///
+    /// If you encounter this, consider updating this library or opening an issue.
+    Other(&'static str),
+}
+
+impl SecurityPatchLevel {
+    /// Parses a `YYYY-MM-DD` tag into its component numbers, without needing
+    /// a `'static` string since the result holds no reference to `raw`.
+    fn try_parse_date(raw: &str) -> Option<(u16, u8, u8)> {
+        let mut parts = raw.splitn(3, '-');
+        let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return None;
+        };
+        let (Ok(year), Ok(month), Ok(day)) =
+            (year.parse::<u16>(), month.parse::<u8>(), day.parse::<u8>())
+        else {
+            return None;
+        };
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return None;
+        }
+        Some((year, month, day))
+    }
+
+    fn parse(raw: &'static str) -> Self {
+        match Self::try_parse_date(raw) {
+            Some((year, month, day)) => SecurityPatchLevel::Date { year, month, day },
+            None => SecurityPatchLevel::Other(raw),
+        }
+    }
+}
+
+pub(crate) fn get_security_patch_tag() -> Option<SecurityPatchLevel> {
+    crate::backend::security_patch_tag().map(SecurityPatchLevel::parse)
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SecurityPatchLevel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SecurityPatchLevel::Date { year, month, day } => {
+                serializer.serialize_str(&format!("{year:04}-{month:02}-{day:02}"))
+            }
+            SecurityPatchLevel::Other(other) => serializer.serialize_str(other),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SecurityPatchLevel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        if let Some((year, month, day)) = SecurityPatchLevel::try_parse_date(&raw) {
+            return Ok(SecurityPatchLevel::Date { year, month, day });
+        }
+        // Only leak for a genuinely unparseable tag, so repeatedly deserializing
+        // well-formed dates (the common case) doesn't grow the heap unbounded.
+        Ok(SecurityPatchLevel::Other(Box::leak(raw.into_boxed_str())))
+    }
+}
+
+/// A single, structured view of the device's build/security posture.
+///
+/// Combines [`BuildType`] and [`SecurityPatchLevel`] so callers can make a
+/// compile-time-checked decision (e.g. "is this a production, recently
+/// patched device?") instead of comparing raw strings scattered through app
+/// code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SecurityState {
+    pub build_type: BuildType,
+    pub security_patch: Option<SecurityPatchLevel>,
+}
+
+impl SecurityState {
+    /// Snapshots the current build type and security patch level.
+    pub fn collect() -> Self {
+        Self {
+            build_type: get_build_type(),
+            security_patch: get_security_patch_tag(),
+        }
+    }
+
+    /// Whether the running image is a production ([`BuildType::User`]) build.
+    /// Shorthand for `self.build_type.is_production()`.
+    pub fn is_production(&self) -> bool {
+        self.build_type.is_production()
+    }
+}
+
+#[cfg(all(test, feature = "simulator", not(target_env = "ohos")))]
+mod tests {
+    use super::*;
+    use crate::sim;
+
+    #[test]
+    fn build_type_is_production_only_for_user() {
+        let _guard = sim::lock_for_tests();
+        sim::set_build_type(Some("user"));
+        assert_eq!(crate::get_build_type(), BuildType::User);
+        assert!(crate::get_build_type().is_production());
+
+        sim::set_build_type(Some("eng"));
+        assert_eq!(crate::get_build_type(), BuildType::Eng);
+        assert!(!crate::get_build_type().is_production());
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn build_type_falls_back_to_other() {
+        let _guard = sim::lock_for_tests();
+        sim::set_build_type(Some("bespoke"));
+        assert_eq!(crate::get_build_type(), BuildType::Other("bespoke"));
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn build_type_is_unknown_when_absent() {
+        let _guard = sim::lock_for_tests();
+        sim::set_build_type(None);
+        assert_eq!(crate::get_build_type(), BuildType::Unknown);
+        assert!(!crate::get_build_type().is_production());
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn security_patch_tag_parses_date() {
+        let _guard = sim::lock_for_tests();
+        sim::set_security_patch_tag(Some("2024-03-05"));
+        assert_eq!(
+            crate::get_security_patch_tag(),
+            Some(SecurityPatchLevel::Date {
+                year: 2024,
+                month: 3,
+                day: 5
+            })
+        );
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn security_patch_tag_falls_back_to_other() {
+        let _guard = sim::lock_for_tests();
+        sim::set_security_patch_tag(Some("not-a-date"));
+        assert_eq!(
+            crate::get_security_patch_tag(),
+            Some(SecurityPatchLevel::Other("not-a-date"))
+        );
+        sim::reset_to_defaults();
+    }
+
+    #[test]
+    fn security_patch_tag_is_none_when_absent() {
+        let _guard = sim::lock_for_tests();
+        sim::set_security_patch_tag(None);
+        assert_eq!(crate::get_security_patch_tag(), None);
+        sim::reset_to_defaults();
+    }
+}
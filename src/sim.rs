@@ -0,0 +1,230 @@
+//! Simulated backend for use on non-OHOS hosts.
+//!
+//! Mirrors the `sysparam_simulator` POSIX shim used elsewhere in the OpenHarmony
+//! Rust ecosystem: when the `simulator` feature is enabled and the crate is not
+//! actually being built for `target_env = "ohos"`, every public getter is backed
+//! by an in-process table instead of native FFI calls. This lets application and
+//! CI tests exercise device-dependent code paths on a regular Linux/macOS
+//! workstation.
+//!
+//! The table is seeded from `OHOS_SIM_*` environment variables on first access
+//! and can be overridden at runtime with the `set_*` functions below, e.g. for a
+//! single test to pretend to run on a tablet:
+//!
+//! ```
+//! ohos_deviceinfo::sim::set_device_type(Some("tablet"));
+//! assert_eq!(ohos_deviceinfo::get_device_type(), ohos_deviceinfo::OhosDeviceType::Tablet);
+//! ```
+//!
+//! Passing `None` simulates the value being absent, e.g. on a build without an
+//! ISV distribution name:
+//!
+//! ```
+//! ohos_deviceinfo::sim::set_distribution_os_name(None);
+//! assert_eq!(ohos_deviceinfo::DistributionInfo::name(), None);
+//! ```
+use std::sync::{OnceLock, RwLock};
+
+/// Leak an owned string to satisfy the crate's `'static` string contract.
+///
+/// Simulated values are either `'static` string literals (the defaults) or
+/// leaked here each time they are set or read from the environment, so
+/// callers always get a `&'static str` just like the real FFI backend does.
+fn leak(value: &str) -> &'static str {
+    Box::leak(value.to_owned().into_boxed_str())
+}
+
+fn env_or(key: &str, default: &'static str) -> Option<&'static str> {
+    match std::env::var(key) {
+        Ok(value) if !value.is_empty() => Some(leak(&value)),
+        _ => Some(default),
+    }
+}
+
+fn env_u32_or(key: &str, default: u32) -> u32 {
+    std::env::var(key)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+macro_rules! sim_string_fields {
+    ($($field:ident, $setter:ident, $env_key:literal, $default:literal;)+) => {
+        struct SimTable {
+            $($field: Option<&'static str>,)+
+            sdk_api_version: u32,
+            first_api_version: u32,
+            distribution_os_api_version: u32,
+            udid: Option<&'static str>,
+            serial: Option<&'static str>,
+        }
+
+        impl Default for SimTable {
+            fn default() -> Self {
+                Self {
+                    $($field: env_or($env_key, $default),)+
+                    sdk_api_version: env_u32_or("OHOS_SIM_SDK_API_VERSION", 12),
+                    first_api_version: env_u32_or("OHOS_SIM_FIRST_API_VERSION", 9),
+                    distribution_os_api_version: env_u32_or(
+                        "OHOS_SIM_DISTRIBUTION_OS_API_VERSION",
+                        12,
+                    ),
+                    udid: env_or(
+                        "OHOS_SIM_UDID",
+                        "0000000000000000000000000000000000000000000000000000000000000",
+                    ),
+                    serial: env_or("OHOS_SIM_SERIAL", "SN0000000000000"),
+                }
+            }
+        }
+
+        $(
+            #[doc = concat!(
+                "Overrides the simulated value normally seeded from the `",
+                $env_key,
+                "` environment variable. Pass `None` to simulate the value being absent."
+            )]
+            pub fn $setter(value: Option<&str>) {
+                table().write().unwrap().$field = value.map(leak);
+            }
+
+            pub(crate) fn $field() -> Option<&'static str> {
+                table().read().unwrap().$field
+            }
+        )+
+    };
+}
+
+sim_string_fields! {
+    device_type, set_device_type, "OHOS_SIM_DEVICE_TYPE", "default";
+    manufacturer, set_manufacturer, "OHOS_SIM_MANUFACTURER", "HUAWEI";
+    brand, set_brand, "OHOS_SIM_BRAND", "HUAWEI";
+    market_name, set_market_name, "OHOS_SIM_MARKET_NAME", "simulator";
+    product_series, set_product_series, "OHOS_SIM_PRODUCT_SERIES", "ALN";
+    product_model, set_product_model, "OHOS_SIM_PRODUCT_MODEL", "ALN-AL00";
+    software_model, set_software_model, "OHOS_SIM_SOFTWARE_MODEL", "ALN-AL00";
+    hardware_model, set_hardware_model, "OHOS_SIM_HARDWARE_MODEL", "ALN";
+    bootloader_version, set_bootloader_version, "OHOS_SIM_BOOTLOADER_VERSION", "ALN-AL00 1.0.0";
+    abi_list, set_abi_list, "OHOS_SIM_ABI_LIST", "arm64-v8a";
+    security_patch_tag, set_security_patch_tag, "OHOS_SIM_SECURITY_PATCH_TAG", "2024-01-01";
+    display_version, set_display_version, "OHOS_SIM_DISPLAY_VERSION", "5.0.0.100";
+    incremental_version, set_incremental_version, "OHOS_SIM_INCREMENTAL_VERSION", "100";
+    os_release_type, set_os_release_type, "OHOS_SIM_OS_RELEASE_TYPE", "Release";
+    os_full_name, set_os_full_name, "OHOS_SIM_OS_FULL_NAME", "OpenHarmony-5.0.0.100";
+    version_id, set_version_id, "OHOS_SIM_VERSION_ID", "OpenHarmony 5.0.0.100";
+    build_type, set_build_type, "OHOS_SIM_BUILD_TYPE", "user";
+    build_user, set_build_user, "OHOS_SIM_BUILD_USER", "builder";
+    build_host, set_build_host, "OHOS_SIM_BUILD_HOST", "build-host";
+    build_time, set_build_time, "OHOS_SIM_BUILD_TIME", "20240101000000";
+    build_hash, set_build_hash, "OHOS_SIM_BUILD_HASH", "0000000000000000000000000000000000000000";
+    distribution_os_name, set_distribution_os_name, "OHOS_SIM_DISTRIBUTION_OS_NAME", "simulator";
+    distribution_os_version, set_distribution_os_version, "OHOS_SIM_DISTRIBUTION_OS_VERSION", "5.0.0.100";
+    distribution_os_release_type, set_distribution_os_release_type, "OHOS_SIM_DISTRIBUTION_OS_RELEASE_TYPE", "Release";
+}
+
+pub(crate) fn sdk_api_version() -> i32 {
+    table().read().unwrap().sdk_api_version as i32
+}
+
+pub(crate) fn first_api_version() -> i32 {
+    table().read().unwrap().first_api_version as i32
+}
+
+pub(crate) fn distribution_os_api_version() -> i32 {
+    table().read().unwrap().distribution_os_api_version as i32
+}
+
+pub(crate) fn udid() -> Result<String, crate::DeviceInfoError> {
+    table()
+        .read()
+        .unwrap()
+        .udid
+        .map(str::to_owned)
+        .ok_or(crate::DeviceInfoError::Unavailable)
+}
+
+pub(crate) fn serial() -> Result<String, crate::DeviceInfoError> {
+    table()
+        .read()
+        .unwrap()
+        .serial
+        .map(str::to_owned)
+        .ok_or(crate::DeviceInfoError::Unavailable)
+}
+
+/// Overrides the simulated value normally seeded from the `OHOS_SIM_UDID`
+/// environment variable. Pass `None` to simulate the value being absent.
+pub fn set_udid(value: Option<&str>) {
+    table().write().unwrap().udid = value.map(leak);
+}
+
+/// Overrides the simulated value normally seeded from the `OHOS_SIM_SERIAL`
+/// environment variable. Pass `None` to simulate the value being absent.
+pub fn set_serial(value: Option<&str>) {
+    table().write().unwrap().serial = value.map(leak);
+}
+
+/// Overrides the simulated SDK API version (see [`crate::get_sdk_api_version()`]).
+pub fn set_sdk_api_version(value: u32) {
+    table().write().unwrap().sdk_api_version = value;
+}
+
+/// Overrides the simulated first API version (see [`crate::get_first_api_version()`]).
+pub fn set_first_api_version(value: u32) {
+    table().write().unwrap().first_api_version = value;
+}
+
+/// Overrides the simulated distribution API version (see [`crate::DistributionInfo::api_version()`]).
+pub fn set_distribution_os_api_version(value: u32) {
+    table().write().unwrap().distribution_os_api_version = value;
+}
+
+/// Resets every simulated value back to its default, re-reading `OHOS_SIM_*` environment
+/// variables as if the process had just started.
+pub fn reset_to_defaults() {
+    *table().write().unwrap() = SimTable::default();
+}
+
+static TABLE: OnceLock<RwLock<SimTable>> = OnceLock::new();
+
+fn table() -> &'static RwLock<SimTable> {
+    TABLE.get_or_init(|| RwLock::new(SimTable::default()))
+}
+
+/// Serializes tests that mutate the simulated table, which is process-global
+/// state shared by every test in the test binary. Call at the start of any test that sets
+/// sim values, and hold the guard for the test's duration.
+#[cfg(test)]
+pub(crate) fn lock_for_tests() -> std::sync::MutexGuard<'static, ()> {
+    static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn setter_round_trips_value() {
+        let _guard = lock_for_tests();
+        set_device_type(Some("tablet"));
+        assert_eq!(device_type(), Some("tablet"));
+        reset_to_defaults();
+    }
+
+    #[test]
+    fn setter_simulates_absent_value() {
+        let _guard = lock_for_tests();
+        set_distribution_os_name(None);
+        assert_eq!(distribution_os_name(), None);
+        reset_to_defaults();
+    }
+
+    #[test]
+    fn reset_to_defaults_restores_default() {
+        let _guard = lock_for_tests();
+        set_device_type(Some("tv"));
+        reset_to_defaults();
+        assert_eq!(device_type(), Some("default"));
+    }
+}